@@ -1,43 +1,103 @@
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fs;
 use std::sync::Mutex;
-use tauri::{AppHandle, Emitter};
+use tauri::{AppHandle, Emitter, Manager};
 use tauri_plugin_global_shortcut::{GlobalShortcutExt, Shortcut, ShortcutState};
 
+const HOTKEYS_FILE_NAME: &str = "hotkeys.json";
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum HotkeyMode {
+    PushToTalk,
+    Toggle,
+}
+
+impl Default for HotkeyMode {
+    fn default() -> Self {
+        Self::PushToTalk
+    }
+}
+
 #[derive(Default)]
 pub struct GlobalShortcutState {
-    current_shortcut: Mutex<Option<String>>,
+    shortcuts: Mutex<HashMap<String, String>>,
+    modes: Mutex<HashMap<String, HotkeyMode>>,
+    toggle_active: Mutex<HashMap<String, bool>>,
 }
 
 impl GlobalShortcutState {
     pub fn new() -> Self {
         Self {
-            current_shortcut: Mutex::new(None),
+            shortcuts: Mutex::new(HashMap::new()),
+            modes: Mutex::new(HashMap::new()),
+            toggle_active: Mutex::new(HashMap::new()),
         }
     }
 }
 
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum HotkeyErrorKind {
+    Conflict,
+    InvalidFormat,
+    Internal,
+}
+
 #[derive(Debug, Serialize, Deserialize)]
 pub struct HotkeyError {
+    kind: HotkeyErrorKind,
     message: String,
 }
 
 impl HotkeyError {
-    fn new(message: impl Into<String>) -> Self {
+    fn new(kind: HotkeyErrorKind, message: impl Into<String>) -> Self {
         Self {
+            kind,
             message: message.into(),
         }
     }
+
+    fn conflict(message: impl Into<String>) -> Self {
+        Self::new(HotkeyErrorKind::Conflict, message)
+    }
+
+    fn invalid_format(message: impl Into<String>) -> Self {
+        Self::new(HotkeyErrorKind::InvalidFormat, message)
+    }
+
+    fn internal(message: impl Into<String>) -> Self {
+        Self::new(HotkeyErrorKind::Internal, message)
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+struct HotkeyPayload {
+    action: String,
 }
 
 #[tauri::command]
 pub async fn register_global_hotkey(
     app: AppHandle,
     state: tauri::State<'_, GlobalShortcutState>,
+    action_id: String,
     shortcut: String,
 ) -> Result<(), HotkeyError> {
-    // Unregister existing shortcut if any
-    let mut current = state.current_shortcut.lock().unwrap();
-    if let Some(existing) = current.as_ref() {
+    register_hotkey_inner(&app, &state, action_id, shortcut)?;
+    persist_hotkeys(&app, &state);
+    Ok(())
+}
+
+fn register_hotkey_inner(
+    app: &AppHandle,
+    state: &GlobalShortcutState,
+    action_id: String,
+    shortcut: String,
+) -> Result<(), HotkeyError> {
+    // Unregister any existing binding for this action
+    let mut shortcuts = state.shortcuts.lock().unwrap();
+    if let Some(existing) = shortcuts.get(&action_id) {
         if let Ok(sc) = existing.parse::<Shortcut>() {
             let _ = app.global_shortcut().on_shortcut(sc, |_, _, _| {});
             let _ = app.global_shortcut().unregister(sc);
@@ -47,67 +107,294 @@ pub async fn register_global_hotkey(
     // Parse new shortcut
     let parsed_shortcut = shortcut
         .parse::<Shortcut>()
-        .map_err(|e| HotkeyError::new(format!("Invalid shortcut format: {}", e)))?;
+        .map_err(|e| HotkeyError::invalid_format(format!("Invalid shortcut format: {}", e)))?;
+
+    // Make sure no other registration in this app (e.g. another action) already owns it;
+    // is_registered only knows this app's own shortcuts, not grabs by the OS or other apps
+    let already_ours = shortcuts.get(&action_id) == Some(&shortcut);
+    if !already_ours && app.global_shortcut().is_registered(parsed_shortcut) {
+        return Err(HotkeyError::conflict(format!(
+            "Shortcut {} is already registered",
+            shortcut
+        )));
+    }
 
     // Register new shortcut with press and release handlers
     let app_for_handler = app.clone();
+    let action_for_handler = action_id.clone();
 
     app.global_shortcut()
         .on_shortcut(parsed_shortcut, move |_app, shortcut, event| {
-            match event.state() {
-                ShortcutState::Pressed => {
-                    println!("Global hotkey pressed: {:?}", shortcut);
-                    let _ = app_for_handler.emit("hotkey-pressed", ());
-                }
-                ShortcutState::Released => {
-                    println!("Global hotkey released: {:?}", shortcut);
-                    let _ = app_for_handler.emit("hotkey-released", ());
+            let hotkey_state = _app.state::<GlobalShortcutState>();
+            let mode = hotkey_state
+                .modes
+                .lock()
+                .unwrap()
+                .get(&action_for_handler)
+                .copied()
+                .unwrap_or_default();
+
+            match mode {
+                HotkeyMode::PushToTalk => match event.state() {
+                    ShortcutState::Pressed => {
+                        println!("Global hotkey pressed: {:?} ({})", shortcut, action_for_handler);
+                        let _ = app_for_handler.emit(
+                            "hotkey-pressed",
+                            HotkeyPayload {
+                                action: action_for_handler.clone(),
+                            },
+                        );
+                    }
+                    ShortcutState::Released => {
+                        println!("Global hotkey released: {:?} ({})", shortcut, action_for_handler);
+                        let _ = app_for_handler.emit(
+                            "hotkey-released",
+                            HotkeyPayload {
+                                action: action_for_handler.clone(),
+                            },
+                        );
+                    }
+                },
+                HotkeyMode::Toggle => {
+                    if event.state() != ShortcutState::Pressed {
+                        return;
+                    }
+
+                    let mut active = hotkey_state.toggle_active.lock().unwrap();
+                    let is_active = active.entry(action_for_handler.clone()).or_insert(false);
+                    *is_active = !*is_active;
+
+                    let event_name = if *is_active {
+                        "recording-start"
+                    } else {
+                        "recording-stop"
+                    };
+                    println!(
+                        "Global hotkey toggled: {:?} ({}) -> {}",
+                        shortcut, action_for_handler, event_name
+                    );
+                    let _ = app_for_handler.emit(
+                        event_name,
+                        HotkeyPayload {
+                            action: action_for_handler.clone(),
+                        },
+                    );
                 }
             }
         })
-        .map_err(|e| HotkeyError::new(format!("Failed to register shortcut: {}", e)))?;
+        .map_err(|e| HotkeyError::internal(format!("Failed to register shortcut: {}", e)))?;
 
     app.global_shortcut()
         .register(parsed_shortcut)
-        .map_err(|e| HotkeyError::new(format!("Failed to register shortcut: {}", e)))?;
+        .map_err(|e| HotkeyError::internal(format!("Failed to register shortcut: {}", e)))?;
 
-    *current = Some(shortcut);
+    state
+        .modes
+        .lock()
+        .unwrap()
+        .entry(action_id.clone())
+        .or_insert(HotkeyMode::PushToTalk);
+    shortcuts.insert(action_id, shortcut);
     println!("Global hotkey registered successfully");
 
     Ok(())
 }
 
+#[tauri::command]
+pub async fn set_hotkey_mode(
+    app: AppHandle,
+    state: tauri::State<'_, GlobalShortcutState>,
+    action_id: String,
+    mode: HotkeyMode,
+) -> Result<(), HotkeyError> {
+    state.modes.lock().unwrap().insert(action_id.clone(), mode);
+    state.toggle_active.lock().unwrap().remove(&action_id);
+    persist_hotkeys(&app, &state);
+    Ok(())
+}
+
 #[tauri::command]
 pub async fn unregister_global_hotkey(
     app: AppHandle,
     state: tauri::State<'_, GlobalShortcutState>,
+    action_id: String,
 ) -> Result<(), HotkeyError> {
-    let mut current = state.current_shortcut.lock().unwrap();
+    let mut shortcuts = state.shortcuts.lock().unwrap();
 
-    if let Some(shortcut_str) = current.as_ref() {
+    if let Some(shortcut_str) = shortcuts.get(&action_id) {
         if let Ok(shortcut) = shortcut_str.parse::<Shortcut>() {
             // Remove handler
             app.global_shortcut()
                 .on_shortcut(shortcut, |_, _, _| {})
-                .map_err(|e| HotkeyError::new(format!("Failed to remove handler: {}", e)))?;
+                .map_err(|e| HotkeyError::internal(format!("Failed to remove handler: {}", e)))?;
 
             // Unregister
             app.global_shortcut()
                 .unregister(shortcut)
-                .map_err(|e| HotkeyError::new(format!("Failed to unregister: {}", e)))?;
+                .map_err(|e| HotkeyError::internal(format!("Failed to unregister: {}", e)))?;
         }
 
-        *current = None;
-        println!("Global hotkey unregistered");
+        shortcuts.remove(&action_id);
+        state.modes.lock().unwrap().remove(&action_id);
+        state.toggle_active.lock().unwrap().remove(&action_id);
+        println!("Global hotkey unregistered: {}", action_id);
     }
 
+    drop(shortcuts);
+    persist_hotkeys(&app, &state);
+
+    Ok(())
+}
+
+#[tauri::command]
+pub async fn unregister_all_hotkeys(
+    app: AppHandle,
+    state: tauri::State<'_, GlobalShortcutState>,
+) -> Result<(), HotkeyError> {
+    let mut shortcuts = state.shortcuts.lock().unwrap();
+
+    for shortcut_str in shortcuts.values() {
+        if let Ok(shortcut) = shortcut_str.parse::<Shortcut>() {
+            let _ = app.global_shortcut().on_shortcut(shortcut, |_, _, _| {});
+            let _ = app.global_shortcut().unregister(shortcut);
+        }
+    }
+
+    shortcuts.clear();
+    state.modes.lock().unwrap().clear();
+    state.toggle_active.lock().unwrap().clear();
+    println!("All global hotkeys unregistered");
+
+    drop(shortcuts);
+    persist_hotkeys(&app, &state);
+
     Ok(())
 }
 
 #[tauri::command]
 pub async fn get_current_hotkey(
     state: tauri::State<'_, GlobalShortcutState>,
+    action_id: String,
 ) -> Result<Option<String>, HotkeyError> {
-    let current = state.current_shortcut.lock().unwrap();
-    Ok(current.clone())
+    let shortcuts = state.shortcuts.lock().unwrap();
+    Ok(shortcuts.get(&action_id).cloned())
+}
+
+#[tauri::command]
+pub async fn get_all_hotkeys(
+    state: tauri::State<'_, GlobalShortcutState>,
+) -> Result<HashMap<String, String>, HotkeyError> {
+    let shortcuts = state.shortcuts.lock().unwrap();
+    Ok(shortcuts.clone())
+}
+
+#[tauri::command]
+pub async fn is_hotkey_available(app: AppHandle, shortcut: String) -> Result<bool, HotkeyError> {
+    let parsed_shortcut = shortcut
+        .parse::<Shortcut>()
+        .map_err(|e| HotkeyError::invalid_format(format!("Invalid shortcut format: {}", e)))?;
+
+    Ok(!app.global_shortcut().is_registered(parsed_shortcut))
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct SavedHotkey {
+    shortcut: String,
+    mode: HotkeyMode,
+}
+
+fn hotkeys_file_path(app: &AppHandle) -> Option<std::path::PathBuf> {
+    let dir = app.path().app_config_dir().ok()?;
+    Some(dir.join(HOTKEYS_FILE_NAME))
+}
+
+/// Best-effort persistence: a write failure here should never take down a
+/// hotkey registration that otherwise succeeded, so we just log it.
+fn persist_hotkeys(app: &AppHandle, state: &GlobalShortcutState) {
+    let Some(path) = hotkeys_file_path(app) else {
+        eprintln!("Could not resolve app config dir; skipping hotkey persistence");
+        return;
+    };
+
+    let shortcuts = state.shortcuts.lock().unwrap();
+    let modes = state.modes.lock().unwrap();
+    let saved: HashMap<String, SavedHotkey> = shortcuts
+        .iter()
+        .map(|(action_id, shortcut)| {
+            let mode = modes.get(action_id).copied().unwrap_or_default();
+            (
+                action_id.clone(),
+                SavedHotkey {
+                    shortcut: shortcut.clone(),
+                    mode,
+                },
+            )
+        })
+        .collect();
+    drop(modes);
+    drop(shortcuts);
+
+    if let Some(parent) = path.parent() {
+        if let Err(e) = fs::create_dir_all(parent) {
+            eprintln!("Failed to create app config dir for hotkeys: {}", e);
+            return;
+        }
+    }
+
+    match serde_json::to_string_pretty(&saved) {
+        Ok(json) => {
+            if let Err(e) = fs::write(&path, json) {
+                eprintln!("Failed to write saved hotkeys: {}", e);
+            }
+        }
+        Err(e) => eprintln!("Failed to serialize saved hotkeys: {}", e),
+    }
+}
+
+#[tauri::command]
+pub async fn restore_hotkeys(
+    app: AppHandle,
+    state: tauri::State<'_, GlobalShortcutState>,
+) -> Result<Vec<HotkeyError>, HotkeyError> {
+    let Some(path) = hotkeys_file_path(&app) else {
+        return Ok(Vec::new());
+    };
+
+    let contents = match fs::read_to_string(&path) {
+        Ok(contents) => contents,
+        Err(_) => return Ok(Vec::new()),
+    };
+
+    let saved: HashMap<String, SavedHotkey> = serde_json::from_str(&contents)
+        .map_err(|e| HotkeyError::internal(format!("Failed to parse saved hotkeys: {}", e)))?;
+
+    let mut failures = Vec::new();
+
+    for (action_id, entry) in saved {
+        match register_hotkey_inner(&app, &state, action_id.clone(), entry.shortcut) {
+            Ok(()) => {
+                state.modes.lock().unwrap().insert(action_id, entry.mode);
+            }
+            Err(e) => {
+                let _ = app.emit(
+                    "hotkey-registration-failed",
+                    RestoreFailure {
+                        action: action_id,
+                        error: e.message.clone(),
+                        kind: e.kind,
+                    },
+                );
+                failures.push(e);
+            }
+        }
+    }
+
+    Ok(failures)
+}
+
+#[derive(Debug, Clone, Serialize)]
+struct RestoreFailure {
+    action: String,
+    error: String,
+    kind: HotkeyErrorKind,
 }